@@ -1,16 +1,18 @@
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::points::{PointCartesian, PointPolar};
+use crate::points::{Angle, PointCartesian, PointPolar};
 use core::f32::consts::PI;
 
-#[allow(unused)]
-use micromath::F32Ext;
-
 pub const MIN_RADIUS: f32 = 14.0;
 pub const MAX_RADIUS: f32 = 31.0;
 pub const MID_RADIUS: f32 = MIN_RADIUS + (MAX_RADIUS - MIN_RADIUS) / 2.0;
 
 pub mod points;
+pub mod transform;
+
+mod ops;
+
+use crate::transform::Transform;
 
 /// Returned if a coordinate is out of bounds.
 #[derive(Debug, PartialEq)]
@@ -19,6 +21,14 @@ pub enum OutOfBoundsError {
     AboveMaximumRadius { radius: f32, theta: f32 },
     CrossesRotationMax,
     CrossesDeadZone(f32),
+    /// Returned by [`Shape::transformed`] for a [`Shape::CenterArc`] when
+    /// `transform` isn't a similarity (rotation + uniform scale, no shear or
+    /// translation), since anything else can't be represented as a
+    /// `CenterArc` around the origin.
+    UnsupportedArcTransform,
+    /// Returned by [`Shape::points`] when `resolution` isn't a positive
+    /// number of millimeters.
+    InvalidResolution(f32),
 }
 
 #[derive(Debug, PartialEq)]
@@ -28,12 +38,18 @@ pub enum Shape<'a> {
         rotation: Rotation,
     },
     Polygon(&'a [PointCartesian]),
+    QuadraticBezier {
+        control: [PointCartesian; 3],
+    },
+    CubicBezier {
+        control: [PointCartesian; 4],
+    },
 }
 
 impl<'a> Shape<'a> {
     /// Returns an arc with a starting point of zero theta.
     pub fn circle(radius: f32) -> Result<Self, OutOfBoundsError> {
-        let point = PointPolar::try_new(radius, 0.0)?;
+        let point = PointPolar::try_new(radius, Angle::from_radians(0.0))?;
 
         Ok(Self::CenterArc {
             point,
@@ -41,10 +57,294 @@ impl<'a> Shape<'a> {
         })
     }
 
+    /// Maps the shape through `transform`, re-validating the result against
+    /// `MIN_RADIUS`/`MAX_RADIUS`.
+    ///
+    /// Bezier control points are mapped without any validation; the chords
+    /// built from them are checked by [`Shape::flatten`].
+    ///
+    /// A [`Shape::Polygon`] writes its transformed vertices into `buffer` (which
+    /// must hold at least as many points as the polygon has vertices) and
+    /// borrows from it.
+    ///
+    /// A [`Shape::CenterArc`] stays centered on the origin, so only the
+    /// rotation and (uniform) scale of `transform` are applied — the start and
+    /// end angles both rotate by the same amount and the radius scales by the
+    /// linear part. `transform` must be a similarity (rotation plus a single
+    /// uniform scale, no shear or translation); anything else — an ellipse
+    /// from non-uniform scale, a shear, or a translation off the origin —
+    /// can't be represented as a `CenterArc` and returns
+    /// [`OutOfBoundsError::UnsupportedArcTransform`].
+    pub fn transformed<'b>(
+        &self,
+        transform: &Transform,
+        buffer: &'b mut [PointCartesian],
+    ) -> Result<Shape<'b>, OutOfBoundsError> {
+        match self {
+            Shape::CenterArc { point, rotation } => {
+                if !transform.is_similarity() {
+                    return Err(OutOfBoundsError::UnsupportedArcTransform);
+                }
+                let rotated = Angle::from_radians(transform.rotation_angle());
+                // Uniform-scale factor of the linear part.
+                let scale = ops::hypot(transform.a, transform.b);
+                let mapped = PointPolar::try_new(point.radius * scale, point.theta + rotated)?;
+                // Rotate start and end by the same amount to preserve the sweep.
+                let rotation = match rotation {
+                    Rotation::Full => Rotation::Full,
+                    Rotation::Partial(end) => Rotation::Partial(*end + rotated),
+                };
+                Ok(Shape::CenterArc {
+                    point: mapped,
+                    rotation,
+                })
+            }
+            Shape::Polygon(vertices) => {
+                let count = vertices.len();
+                for (slot, vertex) in buffer.iter_mut().zip(vertices.iter()) {
+                    let mapped = transform.apply(vertex);
+                    // Re-validate that the mapped vertex stays in the workspace.
+                    mapped.as_polar()?;
+                    *slot = mapped;
+                }
+                Ok(Shape::Polygon(&buffer[..count]))
+            }
+            Shape::QuadraticBezier { control } => Ok(Shape::QuadraticBezier {
+                control: [
+                    transform.apply(&control[0]),
+                    transform.apply(&control[1]),
+                    transform.apply(&control[2]),
+                ],
+            }),
+            Shape::CubicBezier { control } => Ok(Shape::CubicBezier {
+                control: [
+                    transform.apply(&control[0]),
+                    transform.apply(&control[1]),
+                    transform.apply(&control[2]),
+                    transform.apply(&control[3]),
+                ],
+            }),
+        }
+    }
+
+    /// Walks the shape, emitting polar waypoints spaced roughly `resolution`
+    /// millimeters apart so a motion controller can follow it.
+    ///
+    /// A [`Shape::CenterArc`] derives its substep count from the arc length
+    /// (`radius * sweep`); a [`Shape::Polygon`] chains the [`Segment::step`]
+    /// output of each edge between consecutive vertices (the closing edge from
+    /// the last vertex back to the first is not walked), propagating the
+    /// [`OutOfBoundsError`] of any edge
+    /// that leaves the workspace. `resolution` must be a positive number of
+    /// millimeters, or this returns
+    /// [`OutOfBoundsError::InvalidResolution`].
+    pub fn points(&self, resolution: f32) -> Result<ShapePoints<'_>, OutOfBoundsError> {
+        if resolution.is_nan() || resolution <= 0.0 {
+            return Err(OutOfBoundsError::InvalidResolution(resolution));
+        }
+
+        match self {
+            Shape::CenterArc { point, rotation } => {
+                let start = point.theta.radians();
+                let sweep = match rotation {
+                    Rotation::Full => 2.0 * PI,
+                    Rotation::Partial(end) => end.radians() - start,
+                };
+                let arc_length = point.radius * ops::abs(sweep);
+                let steps = substeps(arc_length, resolution);
+
+                Ok(ShapePoints(ShapePointsInner::Arc {
+                    radius: point.radius,
+                    start,
+                    delta: sweep / steps as f32,
+                    steps,
+                    k: 0,
+                }))
+            }
+            Shape::Polygon(vertices) => {
+                // Validate every edge up front so the iterator itself is
+                // infallible.
+                for pair in vertices.windows(2) {
+                    Segment::try_new(pair[0], pair[1])?;
+                }
+
+                Ok(ShapePoints(ShapePointsInner::Polygon {
+                    vertices,
+                    resolution,
+                    seg: 0,
+                    k: 0,
+                    steps: 0,
+                    started: false,
+                }))
+            }
+            // Bezier curves have no direct polar parameterization; flatten them
+            // into segments with `Shape::flatten` and walk those instead.
+            Shape::QuadraticBezier { .. } | Shape::CubicBezier { .. } => {
+                Ok(ShapePoints(ShapePointsInner::Empty))
+            }
+        }
+    }
+
+    /// Flattens a Bezier curve into plottable [`Segment`]s, writing them into
+    /// `buffer` and returning the filled prefix.
+    ///
+    /// Uses adaptive de Casteljau subdivision: a sub-curve is emitted as a
+    /// chord once its interior control points lie within `tolerance` of the
+    /// chord, otherwise it is split at its midpoint and each half is flattened.
+    /// Each emitted chord is built with [`Segment::try_new`], so the rotation-max
+    /// and dead-zone checks still apply; chord endpoints are not checked against
+    /// `MAX_RADIUS`. Non-curve shapes flatten to nothing; walk
+    /// those with [`Shape::points`] instead.
+    pub fn flatten<'b>(
+        &self,
+        tolerance: f32,
+        buffer: &'b mut [Segment],
+    ) -> Result<&'b [Segment], OutOfBoundsError> {
+        let cubic = match self {
+            Shape::CubicBezier { control } => *control,
+            Shape::QuadraticBezier { control } => elevate_quadratic(control),
+            _ => return Ok(&buffer[..0]),
+        };
+
+        // An explicit stack keeps the subdivision `no_std`- and alloc-free.
+        const STACK: usize = 32;
+        let mut stack = [[PointCartesian::new(0.0, 0.0); 4]; STACK];
+        let mut depth = 0;
+        stack[depth] = cubic;
+        depth += 1;
+
+        let mut count = 0;
+        while depth > 0 {
+            // Stop once the buffer is full and return the chords gathered so
+            // far rather than writing past the end.
+            if count == buffer.len() {
+                break;
+            }
+
+            depth -= 1;
+            let curve = stack[depth];
+
+            // Emit once flat, or when splitting further would overflow the
+            // subdivision stack.
+            if is_flat(&curve, tolerance) || depth + 2 > STACK {
+                buffer[count] = Segment::try_new(curve[0], curve[3])?;
+                count += 1;
+                continue;
+            }
+
+            let (left, right) = split_cubic(&curve);
+            stack[depth] = right;
+            depth += 1;
+            stack[depth] = left;
+            depth += 1;
+        }
+
+        Ok(&buffer[..count])
+    }
+
+    /// Builds a polygon, checking that every vertex lies within
+    /// `[MIN_RADIUS, MAX_RADIUS]` and that no edge — including the closing edge
+    /// back to the first vertex — crosses the dead zone or rotation max, by
+    /// running [`Segment::try_new`] over consecutive vertices.
+    pub fn polygon(vertices: &'a [PointCartesian]) -> Result<Self, OutOfBoundsError> {
+        for vertex in vertices {
+            vertex.as_polar()?;
+        }
+        for pair in vertices.windows(2) {
+            Segment::try_new(pair[0], pair[1])?;
+        }
+        if vertices.len() >= 3 {
+            Segment::try_new(vertices[vertices.len() - 1], vertices[0])?;
+        }
+
+        Ok(Shape::Polygon(vertices))
+    }
+
+    /// The signed area of a polygon via the shoelace sum; positive when the
+    /// vertices wind counter-clockwise. Non-polygon shapes and degenerate
+    /// polygons return zero.
+    pub fn signed_area(&self) -> f32 {
+        let vertices = self.vertices();
+        let n = vertices.len();
+        if n < 3 {
+            return 0.0;
+        }
+
+        let mut sum = 0.0;
+        for i in 0..n {
+            sum += vertices[i].cross(&vertices[(i + 1) % n]);
+        }
+        0.5 * sum
+    }
+
+    /// The area centroid of a polygon, or `None` for a degenerate or
+    /// non-polygon shape.
+    pub fn centroid(&self) -> Option<PointCartesian> {
+        let vertices = self.vertices();
+        let n = vertices.len();
+        let area = self.signed_area();
+        if n < 3 || area == 0.0 {
+            return None;
+        }
+
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for i in 0..n {
+            let (p, q) = (vertices[i], vertices[(i + 1) % n]);
+            let cross = p.cross(&q);
+            cx += (p.x + q.x) * cross;
+            cy += (p.y + q.y) * cross;
+        }
+        Some(PointCartesian::new(cx / (6.0 * area), cy / (6.0 * area)))
+    }
+
+    /// The winding order of a polygon, derived from the sign of its area.
+    pub fn orientation(&self) -> Orientation {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Orientation::CounterClockwise
+        } else if area < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Degenerate
+        }
+    }
+
+    /// Whether `point` lies inside a polygon, by counting ray crossings. Always
+    /// false for non-polygon shapes or fewer than three vertices.
+    pub fn contains(&self, point: &PointCartesian) -> bool {
+        let vertices = self.vertices();
+        let n = vertices.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut inside = false;
+        let mut j = n - 1;
+        for i in 0..n {
+            let (pi, pj) = (&vertices[i], &vertices[j]);
+            if (pi.y > point.y) != (pj.y > point.y) {
+                let crossing = (pj.x - pi.x) * (point.y - pi.y) / (pj.y - pi.y) + pi.x;
+                if point.x < crossing {
+                    inside = !inside;
+                }
+            }
+            j = i;
+        }
+        inside
+    }
+
+    /// The vertex slice of a polygon, or an empty slice for other shapes.
+    fn vertices(&self) -> &[PointCartesian] {
+        match self {
+            Shape::Polygon(vertices) => vertices,
+            _ => &[],
+        }
+    }
+
     pub fn center_arc(point: PointPolar, arc_length: f32) -> Result<Self, OutOfBoundsError> {
-        let angle = arc_length + point.theta;
+        let angle = point.theta + Angle::from_radians(arc_length);
 
-        if angle > 2.0 * PI {
+        if angle.radians() > 2.0 * PI {
             Err(OutOfBoundsError::CrossesRotationMax)
         } else {
             Ok(Self::CenterArc {
@@ -58,12 +358,170 @@ impl<'a> Shape<'a> {
 #[derive(Debug, PartialEq)]
 pub enum Rotation {
     Full,
-    Partial(f32),
+    Partial(Angle),
+}
+
+/// The winding order of a polygon, as reported by [`Shape::orientation`].
+#[derive(Debug, PartialEq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Degenerate,
+}
+
+/// Perpendicular distance from `point` to the infinite line through `a` and
+/// `b`, the area of the spanned parallelogram divided by the base length.
+fn point_line_distance(a: &PointCartesian, b: &PointCartesian, point: &PointCartesian) -> f32 {
+    let direction = *b - *a;
+    ops::abs(direction.cross(&(*point - *a))) / direction.norm()
+}
+
+/// Raises a quadratic Bezier to its equivalent cubic control points.
+fn elevate_quadratic(control: &[PointCartesian; 3]) -> [PointCartesian; 4] {
+    let [p0, p1, p2] = *control;
+    [
+        p0,
+        p0 + (p1 - p0) * (2.0 / 3.0),
+        p2 + (p1 - p2) * (2.0 / 3.0),
+        p2,
+    ]
+}
+
+/// A cubic is flat enough once both interior control points are within
+/// `tolerance` of the chord from the first to the last control point.
+fn is_flat(control: &[PointCartesian; 4], tolerance: f32) -> bool {
+    let [p0, p1, p2, p3] = control;
+    // A degenerate chord can't define a line; fall back to the control spread.
+    if (*p3 - *p0).norm() == 0.0 {
+        return (*p1 - *p0).norm() <= tolerance && (*p2 - *p0).norm() <= tolerance;
+    }
+    point_line_distance(p0, p3, p1) <= tolerance && point_line_distance(p0, p3, p2) <= tolerance
+}
+
+/// Splits a cubic Bezier at its midpoint via de Casteljau subdivision.
+fn split_cubic(control: &[PointCartesian; 4]) -> ([PointCartesian; 4], [PointCartesian; 4]) {
+    let [p0, p1, p2, p3] = *control;
+    let a = (p0 + p1) * 0.5;
+    let b = (p1 + p2) * 0.5;
+    let c = (p2 + p3) * 0.5;
+    let d = (a + b) * 0.5;
+    let e = (b + c) * 0.5;
+    let f = (d + e) * 0.5;
+
+    ([p0, a, d, f], [f, e, c, p3])
+}
+
+/// Number of substeps needed to cover `length` at roughly `resolution` spacing,
+/// always at least one. Callers must ensure `resolution > 0.0`, as
+/// [`Shape::points`] does.
+fn substeps(length: f32, resolution: f32) -> u32 {
+    let steps = (length / resolution) as u32;
+    if steps == 0 {
+        1
+    } else {
+        steps
+    }
+}
+
+/// Iterator produced by [`Shape::points`], yielding evenly spaced polar
+/// waypoints along the shape.
+pub struct ShapePoints<'a>(ShapePointsInner<'a>);
+
+enum ShapePointsInner<'a> {
+    Empty,
+    Arc {
+        radius: f32,
+        start: f32,
+        delta: f32,
+        steps: u32,
+        k: u32,
+    },
+    Polygon {
+        vertices: &'a [PointCartesian],
+        resolution: f32,
+        seg: usize,
+        k: u32,
+        steps: u32,
+        started: bool,
+    },
+}
+
+impl Iterator for ShapePoints<'_> {
+    type Item = PointPolar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            ShapePointsInner::Empty => None,
+            ShapePointsInner::Arc {
+                radius,
+                start,
+                delta,
+                steps,
+                k,
+            } => {
+                if *k > *steps {
+                    return None;
+                }
+                let theta = Angle::from_radians(*start + *k as f32 * *delta);
+                *k += 1;
+                // The arc radius was validated when its `PointPolar` was built.
+                Some(PointPolar::try_new(*radius, theta).unwrap())
+            }
+            ShapePointsInner::Polygon {
+                vertices,
+                resolution,
+                seg,
+                k,
+                steps,
+                started,
+            } => loop {
+                if *seg + 1 >= vertices.len() {
+                    return None;
+                }
+
+                // Every edge was validated in `Shape::points`, so the rebuilt
+                // segment is always in bounds.
+                let segment = Segment::try_new(vertices[*seg], vertices[*seg + 1]).unwrap();
+                let length = segment.distance();
+
+                if !*started {
+                    *steps = substeps(length, *resolution);
+                    // Skip the shared start vertex on every edge but the first.
+                    *k = if *seg == 0 { 0 } else { 1 };
+                    *started = true;
+                }
+
+                if *k <= *steps {
+                    let distance = *k as f32 * (length / *steps as f32);
+                    *k += 1;
+                    return segment.step(distance);
+                }
+
+                *seg += 1;
+                *started = false;
+            },
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Segment(PointCartesian, PointCartesian);
 
+/// An ordered plan of moves that keeps the tool outside the dead zone,
+/// produced by [`Segment::plan_around_dead_zone`].
+#[derive(Debug, PartialEq)]
+pub enum PathPlan {
+    /// The straight move is feasible as-is.
+    Direct(Segment),
+    /// The straight move dips inside `MIN_RADIUS`, so it is split into a line
+    /// to the inner boundary, an arc hugging it, and a line to the destination.
+    Detour {
+        entry: Segment,
+        arc: Shape<'static>,
+        exit: Segment,
+    },
+}
+
 impl Segment {
     pub fn try_new(
         point_a: PointCartesian,
@@ -74,24 +532,34 @@ impl Segment {
             .check_dead_zone()
     }
 
+    /// Builds a segment without the dead-zone check, for callers who intend
+    /// to route around it with [`Segment::plan_around_dead_zone`] instead of
+    /// rejecting the move outright.
+    ///
+    /// The rotation-max check still applies, since `plan_around_dead_zone`
+    /// doesn't account for that seam.
+    pub fn new_unchecked(
+        point_a: PointCartesian,
+        point_b: PointCartesian,
+    ) -> Result<Self, OutOfBoundsError> {
+        Self(point_a, point_b).check_rotation_max()
+    }
+
     fn check_rotation_max(self) -> Result<Self, OutOfBoundsError> {
         let (point_a, point_b) = (&self.0, &self.1);
 
-        if !point_a.x.is_sign_negative() && !point_b.x.is_sign_negative() {
-            if point_a.y.is_sign_negative() ^ point_b.y.is_sign_negative() {
-                return Err(OutOfBoundsError::CrossesRotationMax);
-            }
+        if !point_a.x.is_sign_negative()
+            && !point_b.x.is_sign_negative()
+            && (point_a.y.is_sign_negative() ^ point_b.y.is_sign_negative())
+        {
+            return Err(OutOfBoundsError::CrossesRotationMax);
         }
 
         Ok(self)
     }
 
     fn check_dead_zone(self) -> Result<Self, OutOfBoundsError> {
-        let (point_a, point_b) = (&self.0, &self.1);
-
-        let distance = ((point_a.x - point_b.x) * point_a.y + (point_b.y - point_a.y) * point_a.x)
-            .abs()
-            / ((point_b.x - point_a.x).powi(2) + (point_a.y - point_b.y).powi(2)).sqrt();
+        let distance = point_line_distance(&self.0, &self.1, &PointCartesian::new(0.0, 0.0));
 
         if distance < MIN_RADIUS {
             return Err(OutOfBoundsError::CrossesDeadZone(distance));
@@ -107,17 +575,94 @@ impl Segment {
         }
         let (point_a, point_b) = (&self.0, &self.1);
 
-        let x = point_a.x - distance * (point_a.x - point_b.x) / step_distance;
-        let y = point_a.y - distance * (point_a.y - point_b.y) / step_distance;
+        let point = *point_a + (*point_b - *point_a) * (distance / step_distance);
 
         // Points within segment are assumed to have been checked for being within bounds.
-        Some(PointCartesian::new(x, y).as_polar().unwrap())
+        Some(point.as_polar().unwrap())
     }
 
     fn distance(&self) -> f32 {
-        let (point_a, point_b) = (&self.0, &self.1);
+        (self.1 - self.0).norm()
+    }
 
-        ((point_b.x - point_a.x).powi(2) + (point_b.y - point_a.y).powi(2)).sqrt()
+    /// Maps both endpoints through `transform`, re-validating the result
+    /// against the workspace bounds via [`Segment::try_new`].
+    pub fn transformed(&self, transform: &Transform) -> Result<Self, OutOfBoundsError> {
+        let (point_a, point_b) = (transform.apply(&self.0), transform.apply(&self.1));
+        // Both endpoints must stay within `[MIN_RADIUS, MAX_RADIUS]`.
+        point_a.as_polar()?;
+        point_b.as_polar()?;
+        Self::try_new(point_a, point_b)
+    }
+
+    /// Plans a feasible path for this move.
+    ///
+    /// When the straight move stays outside `MIN_RADIUS` the plan is a single
+    /// [`PathPlan::Direct`] move. Otherwise the infeasible middle is replaced by
+    /// a detour: a line to the point where the move enters the `MIN_RADIUS`
+    /// circle, a [`Shape::CenterArc`] hugging that inner boundary, and a line
+    /// from the exit point to the destination.
+    ///
+    /// Since [`Segment::try_new`] rejects exactly the segments this method is
+    /// meant to route around, callers who got back
+    /// [`OutOfBoundsError::CrossesDeadZone`] should rebuild the segment with
+    /// [`Segment::new_unchecked`] before calling this.
+    pub fn plan_around_dead_zone(&self) -> Result<PathPlan, OutOfBoundsError> {
+        let (point_a, point_b) = (self.0, self.1);
+        let direction = point_b - point_a;
+
+        // Closest approach of the move's line to the origin.
+        let distance = ops::abs(direction.cross(&point_a)) / direction.norm();
+        if distance >= MIN_RADIUS {
+            return Ok(PathPlan::Direct(Segment(point_a, point_b)));
+        }
+
+        // Intersect the line `a + t*direction` with the `MIN_RADIUS` circle by
+        // solving |a + t*direction|² = MIN_RADIUS².
+        let a_dot_u = point_a.dot(&direction);
+        let u_sq = direction.dot(&direction);
+        let discriminant = a_dot_u * a_dot_u - u_sq * (point_a.dot(&point_a) - MIN_RADIUS * MIN_RADIUS);
+        if discriminant < 0.0 {
+            return Ok(PathPlan::Direct(Segment(point_a, point_b)));
+        }
+
+        let root = ops::sqrt(discriminant);
+        // Clamp both crossings to the segment's extent so the detour stays
+        // between the original endpoints.
+        let t_entry = ((-a_dot_u - root) / u_sq).clamp(0.0, 1.0);
+        let t_exit = ((-a_dot_u + root) / u_sq).clamp(0.0, 1.0);
+
+        // The infinite line crosses the circle, but if the clamped interval
+        // is empty the segment's own span never actually enters it — the
+        // closest approach happens beyond one of the endpoints.
+        if t_entry >= t_exit {
+            return Ok(PathPlan::Direct(Segment(point_a, point_b)));
+        }
+
+        let entry = point_a + direction * t_entry;
+        let exit = point_a + direction * t_exit;
+
+        // Pin both boundary points to exactly `MIN_RADIUS` so float drift can't
+        // push them just inside the dead zone.
+        let entry_theta = Angle::from_radians(ops::atan2(entry.y, entry.x));
+        let exit_theta = Angle::from_radians(ops::atan2(exit.y, exit.x));
+
+        // Take the shorter way around the boundary so the detour hugs the same
+        // side the move was on instead of sweeping back across the dead zone.
+        let mut sweep = (exit_theta - entry_theta).normalized().radians();
+        if sweep > PI {
+            sweep -= 2.0 * PI;
+        }
+
+        let arc = Shape::center_arc(PointPolar::try_new(MIN_RADIUS, entry_theta)?, sweep)?;
+
+        // Validate both straight sub-moves for the rotation-max seam; the
+        // dead-zone check is skipped deliberately because these legs only touch
+        // the `MIN_RADIUS` boundary at their inner endpoint.
+        let entry = Segment(point_a, entry).check_rotation_max()?;
+        let exit = Segment(exit, point_b).check_rotation_max()?;
+
+        Ok(PathPlan::Detour { entry, arc, exit })
     }
 }
 
@@ -132,7 +677,7 @@ mod tests {
         assert_eq!(
             Shape::circle(radius)?,
             Shape::CenterArc {
-                point: PointPolar::try_new(radius, 0.0)?,
+                point: PointPolar::try_new(radius, Angle::from_radians(0.0))?,
                 rotation: Rotation::Full
             }
         );
@@ -142,12 +687,12 @@ mod tests {
 
     #[test]
     fn test_make_arc() -> Result<(), OutOfBoundsError> {
-        let point = PointPolar::try_new(MIN_RADIUS, 2.0)?;
+        let point = PointPolar::try_new(MIN_RADIUS, Angle::from_radians(2.0))?;
         assert_eq!(
             Shape::center_arc(point.clone(), PI)?,
             Shape::CenterArc {
                 point,
-                rotation: Rotation::Partial(PI + 2.0)
+                rotation: Rotation::Partial(Angle::from_radians(PI + 2.0))
             }
         );
 
@@ -200,6 +745,265 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_polygon_constructor() -> Result<(), OutOfBoundsError> {
+        let vertices = [
+            PointCartesian::new(15.0, 15.0),
+            PointCartesian::new(20.0, 15.0),
+            PointCartesian::new(20.0, 20.0),
+            PointCartesian::new(15.0, 20.0),
+        ];
+
+        assert_eq!(Shape::polygon(&vertices)?, Shape::Polygon(&vertices[..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_measures() {
+        let vertices = [
+            PointCartesian::new(15.0, 15.0),
+            PointCartesian::new(20.0, 15.0),
+            PointCartesian::new(20.0, 20.0),
+            PointCartesian::new(15.0, 20.0),
+        ];
+        let polygon = Shape::Polygon(&vertices);
+
+        assert_eq!(polygon.signed_area(), 25.0);
+        assert_eq!(polygon.orientation(), Orientation::CounterClockwise);
+        assert_eq!(polygon.centroid(), Some(PointCartesian::new(17.5, 17.5)));
+        assert!(polygon.contains(&PointCartesian::new(17.5, 17.5)));
+        assert!(!polygon.contains(&PointCartesian::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_flatten_cubic_line() -> Result<(), OutOfBoundsError> {
+        // All four control points are collinear on `y = MIN_RADIUS`, so the
+        // curve is already flat and collapses to a single chord.
+        let curve = Shape::CubicBezier {
+            control: [
+                PointCartesian::new(MIN_RADIUS, MIN_RADIUS),
+                PointCartesian::new(5.0, MIN_RADIUS),
+                PointCartesian::new(-5.0, MIN_RADIUS),
+                PointCartesian::new(-MIN_RADIUS, MIN_RADIUS),
+            ],
+        };
+        let mut buffer: [Segment; 16] =
+            core::array::from_fn(|_| Segment(PointCartesian::new(0.0, 0.0), PointCartesian::new(0.0, 0.0)));
+
+        assert_eq!(curve.flatten(0.1, &mut buffer)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_buffer_full() -> Result<(), OutOfBoundsError> {
+        // A bowed cubic needs many chords; with only three slots the flattening
+        // must stop at the buffer's capacity instead of writing past the end.
+        let curve = Shape::CubicBezier {
+            control: [
+                PointCartesian::new(MID_RADIUS, MID_RADIUS),
+                PointCartesian::new(0.0, MAX_RADIUS),
+                PointCartesian::new(0.0, MAX_RADIUS),
+                PointCartesian::new(-MID_RADIUS, MID_RADIUS),
+            ],
+        };
+        let mut buffer: [Segment; 3] =
+            core::array::from_fn(|_| Segment(PointCartesian::new(0.0, 0.0), PointCartesian::new(0.0, 0.0)));
+
+        assert_eq!(curve.flatten(0.01, &mut buffer)?.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_quadratic_subdivides() -> Result<(), OutOfBoundsError> {
+        // A bowed quadratic needs more than one chord to stay within tolerance.
+        let curve = Shape::QuadraticBezier {
+            control: [
+                PointCartesian::new(MID_RADIUS, MID_RADIUS),
+                PointCartesian::new(0.0, MAX_RADIUS),
+                PointCartesian::new(-MID_RADIUS, MID_RADIUS),
+            ],
+        };
+        let mut buffer: [Segment; 32] =
+            core::array::from_fn(|_| Segment(PointCartesian::new(0.0, 0.0), PointCartesian::new(0.0, 0.0)));
+
+        assert!(curve.flatten(0.5, &mut buffer)?.len() > 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_unchecked_allows_dead_zone_but_not_rotation_max() {
+        let crosses_dead_zone = Segment::new_unchecked(
+            PointCartesian::new(-MID_RADIUS, MIN_RADIUS - 1.0),
+            PointCartesian::new(MID_RADIUS, MIN_RADIUS - 1.0),
+        );
+        assert!(crosses_dead_zone.is_ok());
+
+        let crosses_rotation_max = Segment::new_unchecked(
+            PointCartesian::new(MID_RADIUS, 1.0),
+            PointCartesian::new(MID_RADIUS, -1.0),
+        );
+        assert_eq!(
+            crosses_rotation_max,
+            Err(OutOfBoundsError::CrossesRotationMax)
+        );
+    }
+
+    #[test]
+    fn test_plan_direct() -> Result<(), OutOfBoundsError> {
+        let segment = Segment::try_new(
+            PointCartesian::new(MID_RADIUS, 0.0),
+            PointCartesian::new(0.0, MID_RADIUS),
+        )?;
+
+        match segment.plan_around_dead_zone()? {
+            PathPlan::Direct(_) => Ok(()),
+            PathPlan::Detour { .. } => panic!("Feasible move should not detour."),
+        }
+    }
+
+    #[test]
+    fn test_plan_detour() -> Result<(), OutOfBoundsError> {
+        // This move passes within 5mm of the origin, well inside the dead
+        // zone, so `try_new` would reject it; build it with `new_unchecked`
+        // instead, the way a caller routing around `CrossesDeadZone` would.
+        let segment = Segment::new_unchecked(
+            PointCartesian::new(-MID_RADIUS, 5.0),
+            PointCartesian::new(MID_RADIUS, 5.0),
+        )?;
+
+        match segment.plan_around_dead_zone()? {
+            PathPlan::Detour { arc, .. } => {
+                match arc {
+                    Shape::CenterArc { point, .. } => assert_eq!(point.radius, MIN_RADIUS),
+                    _ => panic!("Detour arc should be a center arc."),
+                }
+                Ok(())
+            }
+            PathPlan::Direct(_) => panic!("Move through the dead zone should detour."),
+        }
+    }
+
+    #[test]
+    fn test_plan_direct_when_line_nears_but_segment_does_not() -> Result<(), OutOfBoundsError> {
+        // The *infinite* line through these points passes within MIN_RADIUS of
+        // the origin, but both endpoints are ~100mm out and the segment's own
+        // span never approaches the circle — it should plan as direct rather
+        // than inventing a detour back toward the boundary.
+        let segment =
+            Segment::new_unchecked(PointCartesian::new(100.0, 5.0), PointCartesian::new(102.0, 5.0))?;
+
+        match segment.plan_around_dead_zone()? {
+            PathPlan::Direct(_) => Ok(()),
+            PathPlan::Detour { .. } => panic!("Move far from the dead zone should not detour."),
+        }
+    }
+
+    #[test]
+    fn test_shape_points_arc() -> Result<(), OutOfBoundsError> {
+        let circle = Shape::circle(MID_RADIUS)?;
+        let mut points = circle.points(1.0)?;
+
+        assert_eq!(points.next().unwrap().radius, MID_RADIUS);
+        assert!(points.count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shape_points_polygon() -> Result<(), OutOfBoundsError> {
+        let vertices = [
+            PointCartesian::new(MIN_RADIUS, MIN_RADIUS),
+            PointCartesian::new(-MIN_RADIUS, MIN_RADIUS),
+        ];
+        let polygon = Shape::Polygon(&vertices);
+        let mut points = polygon.points(MIN_RADIUS)?;
+
+        assert_eq!(points.next().unwrap(), vertices[0].as_polar()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shape_points_rejects_non_positive_resolution() -> Result<(), OutOfBoundsError> {
+        let circle = Shape::circle(MID_RADIUS)?;
+
+        assert_eq!(
+            circle.points(0.0).err(),
+            Some(OutOfBoundsError::InvalidResolution(0.0))
+        );
+        assert_eq!(
+            circle.points(-1.0).err(),
+            Some(OutOfBoundsError::InvalidResolution(-1.0))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shape_points_polygon_propagates_error() {
+        let vertices = [
+            PointCartesian::new(-MID_RADIUS, MIN_RADIUS - 1.0),
+            PointCartesian::new(MID_RADIUS, MIN_RADIUS - 1.0),
+        ];
+        let polygon = Shape::Polygon(&vertices);
+
+        match polygon.points(1.0) {
+            Err(OutOfBoundsError::CrossesDeadZone(_)) => (),
+            _ => panic!("Edge should cross the dead zone."),
+        }
+    }
+
+    #[test]
+    fn test_segment_transformed_identity() -> Result<(), OutOfBoundsError> {
+        let segment = Segment::try_new(
+            PointCartesian::new(MID_RADIUS, 0.0),
+            PointCartesian::new(0.0, MID_RADIUS),
+        )?;
+
+        assert_eq!(segment, segment.transformed(&Transform::identity())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_center_arc_transformed_rejects_non_uniform_scale() -> Result<(), OutOfBoundsError> {
+        let circle = Shape::circle(MID_RADIUS)?;
+        let mut buffer: [PointCartesian; 0] = [];
+
+        assert_eq!(
+            circle.transformed(&Transform::scale(2.0, 1.0), &mut buffer),
+            Err(OutOfBoundsError::UnsupportedArcTransform)
+        );
+        assert_eq!(
+            circle.transformed(&Transform::translation(1.0, 0.0), &mut buffer),
+            Err(OutOfBoundsError::UnsupportedArcTransform)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_transformed_identity() -> Result<(), OutOfBoundsError> {
+        let vertices = [
+            PointCartesian::new(MIN_RADIUS, 0.0),
+            PointCartesian::new(0.0, MIN_RADIUS),
+            PointCartesian::new(-MIN_RADIUS, 0.0),
+        ];
+        let polygon = Shape::Polygon(&vertices);
+        let mut buffer = [PointCartesian::new(0.0, 0.0); 3];
+
+        assert_eq!(
+            polygon.transformed(&Transform::identity(), &mut buffer)?,
+            Shape::Polygon(&vertices[..])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_segment_distance() -> Result<(), OutOfBoundsError> {
         let segment = Segment::try_new(
@@ -219,7 +1023,7 @@ mod tests {
             PointCartesian::new(-MIN_RADIUS, MIN_RADIUS),
         );
         let (polar_a, polar_b) = (point_a.as_polar()?, point_b.as_polar()?);
-        let polar_middle = PointPolar::try_new(MIN_RADIUS, 0.5 * PI)?;
+        let polar_middle = PointPolar::try_new(MIN_RADIUS, Angle::from_radians(0.5 * PI))?;
         let segment = Segment::try_new(point_a, point_b)?;
 
         assert_eq!(Some(polar_a), segment.step(0.0));
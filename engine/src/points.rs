@@ -1,9 +1,56 @@
+use crate::ops;
 use crate::{OutOfBoundsError, MAX_RADIUS, MIN_RADIUS};
-#[allow(unused)]
-use micromath::F32Ext;
+use core::f32::consts::PI;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// An angle stored internally in radians, keeping unit handling explicit so
+/// degree and radian values can't be silently mixed.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Angle(f32);
+
+impl Angle {
+    pub fn from_radians(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self(degrees * PI / 180.0)
+    }
+
+    /// The angle in radians.
+    pub fn radians(&self) -> f32 {
+        self.0
+    }
+
+    /// The angle in degrees.
+    pub fn to_degrees(&self) -> f32 {
+        self.0 * 180.0 / PI
+    }
+
+    /// Folds the angle into the `[0, 2π)` range.
+    pub fn normalized(&self) -> Self {
+        Self(ops::rem_euclid(self.0, 2.0 * PI))
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
 
 /// Represents points in a cartesian space. `x` and `y` are in milimeters.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct PointCartesian {
     pub x: f32,
     pub y: f32,
@@ -16,27 +63,97 @@ impl PointCartesian {
 
     /// Converts the cratesian point to an equivalent polar point.
     pub fn as_polar(&self) -> Result<PointPolar, OutOfBoundsError> {
-        let radius = self.x.hypot(self.y);
-        let theta = self.y.atan2(self.x);
+        let radius = ops::hypot(self.x, self.y);
+        let theta = Angle::from_radians(ops::atan2(self.y, self.x));
 
         PointPolar::try_new(radius, theta)
     }
+
+    /// The dot product of this point and `other`, treating both as vectors
+    /// from the origin.
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The scalar z-component of the cross product `self × other`.
+    pub fn cross(&self, other: &Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// The distance from the origin, i.e. the magnitude of the vector.
+    pub fn norm(&self) -> f32 {
+        ops::hypot(self.x, self.y)
+    }
+
+    /// Alias for [`norm`](Self::norm).
+    pub fn length(&self) -> f32 {
+        self.norm()
+    }
+
+    /// Returns the point scaled to unit length. A zero-length point is left
+    /// unchanged.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm == 0.0 {
+            *self
+        } else {
+            *self / norm
+        }
+    }
+}
+
+impl Add for PointCartesian {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for PointCartesian {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f32> for PointCartesian {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
 }
 
-/// Represents points in a polar space. `radius` is in milimeters and `theta` is
-/// in degrees.
+impl Div<f32> for PointCartesian {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+/// Represents points in a polar space. `radius` is in milimeters and `theta`
+/// is an [`Angle`].
 #[derive(Debug, PartialEq, Clone)]
 pub struct PointPolar {
     pub radius: f32,
-    pub theta: f32,
+    pub theta: Angle,
 }
 
 impl PointPolar {
-    pub fn try_new(radius: f32, theta: f32) -> Result<Self, OutOfBoundsError> {
+    pub fn try_new(radius: f32, theta: Angle) -> Result<Self, OutOfBoundsError> {
         if radius > MAX_RADIUS {
-            Err(OutOfBoundsError::AboveMaximumRadius { radius, theta })
+            Err(OutOfBoundsError::AboveMaximumRadius {
+                radius,
+                theta: theta.radians(),
+            })
         } else if radius < MIN_RADIUS {
-            Err(OutOfBoundsError::BelowMinimumRadius { radius, theta })
+            Err(OutOfBoundsError::BelowMinimumRadius {
+                radius,
+                theta: theta.radians(),
+            })
         } else {
             Ok(Self { radius, theta })
         }
@@ -67,37 +184,65 @@ mod tests {
             points[0].as_polar()?,
             PointPolar {
                 radius: 15.0,
-                theta: 0.0
+                theta: Angle::from_radians(0.0)
             }
         );
         assert_eq!(
             points[1].as_polar()?,
             PointPolar {
                 radius: 15.0,
-                theta: 0.5 * PI,
+                theta: Angle::from_radians(0.5 * PI),
             }
         );
         assert_eq!(
             points[2].as_polar()?,
             PointPolar {
                 radius: 15.0,
-                theta: PI,
+                theta: Angle::from_radians(PI),
             }
         );
         assert_eq!(
             points[3].as_polar()?,
             PointPolar {
                 radius: 15.0,
-                theta: -0.5 * PI,
+                theta: Angle::from_radians(-0.5 * PI),
             }
         );
 
         Ok(())
     }
 
+    #[test]
+    fn test_angle_units_and_wrapping() {
+        assert!((Angle::from_degrees(180.0).radians() - PI).abs() < 1e-4);
+        assert!((Angle::from_radians(PI).to_degrees() - 180.0).abs() < 1e-3);
+        assert!((Angle::from_radians(-0.5 * PI).normalized().radians() - 1.5 * PI).abs() < 1e-4);
+        assert_eq!(
+            Angle::from_radians(1.0) + Angle::from_radians(2.0),
+            Angle::from_radians(3.0)
+        );
+    }
+
+    #[test]
+    fn test_vector_ops() {
+        let a = PointCartesian::new(3.0, 4.0);
+        let b = PointCartesian::new(1.0, 2.0);
+
+        assert_eq!(a + b, PointCartesian::new(4.0, 6.0));
+        assert_eq!(a - b, PointCartesian::new(2.0, 2.0));
+        assert_eq!(a * 2.0, PointCartesian::new(6.0, 8.0));
+        assert_eq!(a / 2.0, PointCartesian::new(1.5, 2.0));
+        assert_eq!(a.dot(&b), 11.0);
+        assert_eq!(a.cross(&b), 2.0);
+        assert_eq!(a.norm(), 5.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.normalize(), PointCartesian::new(0.6, 0.8));
+        assert_eq!(PointCartesian::new(0.0, 0.0).normalize(), PointCartesian::new(0.0, 0.0));
+    }
+
     #[test]
     fn test_min_radius() {
-        match PointPolar::try_new(MIN_RADIUS - 1.0, 0.0) {
+        match PointPolar::try_new(MIN_RADIUS - 1.0, Angle::from_radians(0.0)) {
             Err(OutOfBoundsError::BelowMinimumRadius { .. }) => (),
             _ => panic!("Radius isn't below minimum!"),
         }
@@ -109,7 +254,7 @@ mod tests {
 
     #[test]
     fn test_max_radius() {
-        match PointPolar::try_new(MAX_RADIUS + 1.0, 0.0) {
+        match PointPolar::try_new(MAX_RADIUS + 1.0, Angle::from_radians(0.0)) {
             Err(OutOfBoundsError::AboveMaximumRadius { .. }) => (),
             _ => panic!("Radius isn't above maximum!"),
         }
@@ -0,0 +1,145 @@
+use crate::ops;
+use crate::points::PointCartesian;
+
+/// A 2D affine transform stored as the six non-trivial entries of a 2×3 matrix.
+///
+/// A point `(x, y)` maps to `(a*x + c*y + e, b*x + d*y + f)`, so `a`/`b`/`c`/`d`
+/// carry the linear (rotation/scale) part and `e`/`f` the translation. Values
+/// are in millimeters, matching the crate's [`PointCartesian`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transform {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Transform {
+    /// The identity transform, leaving points unchanged.
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// A counter-clockwise rotation about the origin by `theta` radians.
+    pub fn rotation(theta: f32) -> Self {
+        let (sin, cos) = (ops::sin(theta), ops::cos(theta));
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// A scaling about the origin by `sx` along x and `sy` along y.
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// A translation by `dx`/`dy` millimeters.
+    pub fn translation(dx: f32, dy: f32) -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: dx,
+            f: dy,
+        }
+    }
+
+    /// Returns the transform that applies `self` first and then `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        Self {
+            a: other.a * self.a + other.c * self.b,
+            b: other.b * self.a + other.d * self.b,
+            c: other.a * self.c + other.c * self.d,
+            d: other.b * self.c + other.d * self.d,
+            e: other.a * self.e + other.c * self.f + other.e,
+            f: other.b * self.e + other.d * self.f + other.f,
+        }
+    }
+
+    /// Alias for [`then`](Self::then).
+    pub fn compose(&self, other: &Self) -> Self {
+        self.then(other)
+    }
+
+    /// Maps a point through the transform.
+    pub fn apply(&self, point: &PointCartesian) -> PointCartesian {
+        PointCartesian::new(
+            self.a * point.x + self.c * point.y + self.e,
+            self.b * point.x + self.d * point.y + self.f,
+        )
+    }
+
+    /// The rotation angle of the linear part, in radians.
+    pub(crate) fn rotation_angle(&self) -> f32 {
+        ops::atan2(self.b, self.a)
+    }
+
+    /// Whether the linear part is a similarity (a rotation composed with a
+    /// single uniform scale, no shear) and there is no translation — the
+    /// precondition for mapping a [`crate::Shape::CenterArc`] onto another
+    /// `CenterArc`.
+    ///
+    /// The linear part `[[a, c], [b, d]]` is a similarity when its columns
+    /// are the same length (uniform scale) and orthogonal (no shear), and its
+    /// determinant is positive (no reflection).
+    pub(crate) fn is_similarity(&self) -> bool {
+        const TOLERANCE: f32 = 1e-4;
+
+        let scale_u = ops::hypot(self.a, self.b);
+        let scale_v = ops::hypot(self.c, self.d);
+        let dot = self.a * self.c + self.b * self.d;
+        let determinant = self.a * self.d - self.b * self.c;
+
+        ops::abs(scale_u - scale_v) <= TOLERANCE
+            && ops::abs(dot) <= TOLERANCE
+            && determinant > 0.0
+            && ops::abs(self.e) <= TOLERANCE
+            && ops::abs(self.f) <= TOLERANCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::PI;
+
+    #[test]
+    fn test_translation_apply() {
+        let transform = Transform::translation(3.0, -2.0);
+        assert_eq!(
+            transform.apply(&PointCartesian::new(1.0, 1.0)),
+            PointCartesian::new(4.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_compose_rotation_then_translation() {
+        let transform = Transform::rotation(0.5 * PI).then(&Transform::translation(5.0, 0.0));
+        let mapped = transform.apply(&PointCartesian::new(1.0, 0.0));
+
+        assert!((mapped.x - 5.0).abs() < 1e-4);
+        assert!((mapped.y - 1.0).abs() < 1e-4);
+    }
+}